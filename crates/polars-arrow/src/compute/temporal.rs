@@ -17,14 +17,16 @@
 
 //! Defines temporal kernels for time and date related functions.
 
-use chrono::{Datelike, Timelike};
-use polars_error::PolarsResult;
+use chrono::{
+    Datelike, LocalResult, NaiveDate, NaiveDateTime, NaiveTime, Offset, TimeDelta, Timelike,
+};
+use polars_error::{polars_bail, polars_ensure, polars_err, PolarsError, PolarsResult};
 
 use super::arity::unary;
 use crate::array::*;
 use crate::datatypes::*;
 use crate::temporal_conversions::*;
-use crate::types::NativeType;
+use crate::types::{months_days_ns, NativeType};
 
 // Create and implement a trait that converts chrono's `Weekday`
 // type into `i8`
@@ -48,6 +50,41 @@ trait Int8IsoWeek: Datelike {
 impl Int8IsoWeek for chrono::NaiveDateTime {}
 impl<T: chrono::TimeZone> Int8IsoWeek for chrono::DateTime<T> {}
 
+// Create and implement a trait that derives the quarter
+// from chrono's `Datelike` month
+trait Int8Quarter: Datelike {
+    fn i8_quarter(&self) -> i8 {
+        (self.month0() / 3 + 1).try_into().unwrap()
+    }
+}
+
+impl Int8Quarter for chrono::NaiveDateTime {}
+impl<T: chrono::TimeZone> Int8Quarter for chrono::DateTime<T> {}
+
+// Create and implement a trait that extracts the sub-second
+// milliseconds from chrono's `Timelike` types
+trait Int32Millisecond: Timelike {
+    fn i32_millisecond(&self) -> i32 {
+        (self.nanosecond() / 1_000_000).try_into().unwrap()
+    }
+}
+
+impl Int32Millisecond for chrono::NaiveDateTime {}
+impl Int32Millisecond for chrono::NaiveTime {}
+impl<T: chrono::TimeZone> Int32Millisecond for chrono::DateTime<T> {}
+
+// Create and implement a trait that extracts the sub-second
+// microseconds from chrono's `Timelike` types
+trait Int32Microsecond: Timelike {
+    fn i32_microsecond(&self) -> i32 {
+        (self.nanosecond() / 1_000).try_into().unwrap()
+    }
+}
+
+impl Int32Microsecond for chrono::NaiveDateTime {}
+impl Int32Microsecond for chrono::NaiveTime {}
+impl<T: chrono::TimeZone> Int32Microsecond for chrono::DateTime<T> {}
+
 // Macro to avoid repetition in functions, that apply
 // `chrono::Datelike` methods on Arrays
 macro_rules! date_like {
@@ -108,6 +145,13 @@ pub fn iso_week(array: &dyn Array) -> PolarsResult<PrimitiveArray<i8>> {
     date_like!(i8_iso_week, array, ArrowDataType::Int8)
 }
 
+/// Extracts the quarter of a temporal array as [`PrimitiveArray<i8>`].
+/// Value ranges from 1 to 4.
+/// Use [`can_quarter`] to check if this operation is supported for the target [`ArrowDataType`].
+pub fn quarter(array: &dyn Array) -> PolarsResult<PrimitiveArray<i8>> {
+    date_like!(i8_quarter, array, ArrowDataType::Int8)
+}
+
 // Macro to avoid repetition in functions, that apply
 // `chrono::Timelike` methods on Arrays
 macro_rules! time_like {
@@ -117,9 +161,7 @@ macro_rules! time_like {
                 date_variants($array, $data_type, |x| x.$extract().try_into().unwrap())
             },
             ArrowDataType::Time32(_) | ArrowDataType::Time64(_) => {
-                time_variants($array, ArrowDataType::UInt32, |x| {
-                    x.$extract().try_into().unwrap()
-                })
+                time_variants($array, $data_type, |x| x.$extract().try_into().unwrap())
             },
             ArrowDataType::Timestamp(time_unit, Some(timezone_str)) => {
                 let array = $array.as_any().downcast_ref().unwrap();
@@ -160,6 +202,22 @@ pub fn second(array: &dyn Array) -> PolarsResult<PrimitiveArray<i8>> {
     time_like!(second, array, ArrowDataType::Int8)
 }
 
+/// Extracts the milliseconds of a temporal array as [`PrimitiveArray<i32>`].
+/// Value ranges from 0 to 1_999.
+/// The range from 1_000 to 1_999 represents the leap second.
+/// Use [`can_millisecond`] to check if this operation is supported for the target [`ArrowDataType`].
+pub fn millisecond(array: &dyn Array) -> PolarsResult<PrimitiveArray<i32>> {
+    time_like!(i32_millisecond, array, ArrowDataType::Int32)
+}
+
+/// Extracts the microseconds of a temporal array as [`PrimitiveArray<i32>`].
+/// Value ranges from 0 to 1_999_999.
+/// The range from 1_000_000 to 1_999_999 represents the leap second.
+/// Use [`can_microsecond`] to check if this operation is supported for the target [`ArrowDataType`].
+pub fn microsecond(array: &dyn Array) -> PolarsResult<PrimitiveArray<i32>> {
+    time_like!(i32_microsecond, array, ArrowDataType::Int32)
+}
+
 /// Extracts the nanoseconds of a temporal array as [`PrimitiveArray<i32>`].
 /// Value ranges from 0 to 1_999_999_999.
 /// The range from 1_000_000_000 to 1_999_999_999 represents the leap second.
@@ -168,6 +226,286 @@ pub fn nanosecond(array: &dyn Array) -> PolarsResult<PrimitiveArray<i32>> {
     time_like!(nanosecond, array, ArrowDataType::Int32)
 }
 
+/// Truncates the values of a temporal array down to a multiple of `every`.
+///
+/// `every` must set exactly one positive component:
+/// * months truncate to the first day of the month, counting months from 1970-01;
+/// * days truncate to midnight, counting days from 1970-01-01;
+/// * nanoseconds truncate to a multiple of that fixed duration since the UNIX epoch.
+///
+/// Timestamps with a timezone are truncated in local time and converted back to UTC,
+/// so truncating to `1d` yields local midnight also across DST transitions. When the
+/// truncated local datetime is ambiguous or non-existent:
+/// * for months and days, the result is the instant the local day starts, i.e. the
+///   earliest candidate, or the end of the gap for days whose midnight is skipped;
+/// * for fixed durations, the result keeps the side of the transition the original
+///   value is on, and a non-existent result is an error.
+///
+/// The output has the same [`ArrowDataType`] as the input.
+/// Use [`can_truncate`] to check if this operation is supported for the target [`ArrowDataType`].
+/// # Errors
+/// This function errors iff:
+/// * the array is not a `Date32`, `Date64` or `Timestamp` array
+/// * `every` is not positive or mixes months, days and nanoseconds
+/// * the nanoseconds of `every` are not a multiple of the array's time unit
+///   (seconds for `Date32`, milliseconds for `Date64`)
+/// * a datetime truncated to a fixed duration does not exist in the array's timezone
+/// * a value or its truncation is outside the range of representable datetimes
+pub fn truncate(array: &dyn Array, every: months_days_ns) -> PolarsResult<Box<dyn Array>> {
+    let data_type = array.data_type().clone();
+    match array.data_type().to_logical_type() {
+        ArrowDataType::Date32 => {
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<i32>>()
+                .unwrap();
+            let interval = Interval::try_new(every, TimeUnit::Second)?;
+            let out = try_unary_valid(
+                array,
+                |x| {
+                    let seconds = interval.truncate(x as i64 * SECONDS_IN_DAY, TimeUnit::Second)?;
+                    Ok(seconds.div_euclid(SECONDS_IN_DAY) as i32)
+                },
+                data_type,
+            )?;
+            Ok(out.boxed())
+        },
+        ArrowDataType::Date64 => {
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<i64>>()
+                .unwrap();
+            let interval = Interval::try_new(every, TimeUnit::Millisecond)?;
+            let out = try_unary_valid(
+                array,
+                |x| interval.truncate(x, TimeUnit::Millisecond),
+                data_type,
+            )?;
+            Ok(out.boxed())
+        },
+        ArrowDataType::Timestamp(time_unit, None) => {
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<i64>>()
+                .unwrap();
+            let interval = Interval::try_new(every, *time_unit)?;
+            let out = try_unary_valid(array, |x| interval.truncate(x, *time_unit), data_type)?;
+            Ok(out.boxed())
+        },
+        ArrowDataType::Timestamp(time_unit, Some(timezone_str)) => {
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<i64>>()
+                .unwrap();
+            let interval = Interval::try_new(every, *time_unit)?;
+
+            let out = if let Ok(timezone) = parse_offset(timezone_str) {
+                truncate_tz_impl(array, *time_unit, timezone, timezone_str, interval)
+            } else {
+                truncate_chrono_tz(array, *time_unit, timezone_str, interval)
+            }?;
+            Ok(out.boxed())
+        },
+        _ => polars_bail!(
+            InvalidOperation: "cannot truncate array of type {:?}", array.data_type()
+        ),
+    }
+}
+
+const SECONDS_IN_DAY: i64 = 86_400;
+
+/// The interval a [`truncate`] floors to.
+#[derive(Clone, Copy)]
+enum Interval {
+    Months(i64),
+    Days(i64),
+    /// A fixed duration, in the time unit of the truncated array.
+    Fixed(i64),
+}
+
+impl Interval {
+    fn try_new(every: months_days_ns, time_unit: TimeUnit) -> PolarsResult<Self> {
+        let interval = match (every.months(), every.days(), every.ns()) {
+            (months, 0, 0) if months > 0 => Self::Months(months as i64),
+            (0, days, 0) if days > 0 => Self::Days(days as i64),
+            (0, 0, ns) if ns > 0 => {
+                let scale = match time_unit {
+                    TimeUnit::Second => 1_000_000_000,
+                    TimeUnit::Millisecond => 1_000_000,
+                    TimeUnit::Microsecond => 1_000,
+                    TimeUnit::Nanosecond => 1,
+                };
+                polars_ensure!(
+                    ns % scale == 0,
+                    ComputeError: "cannot truncate to {}ns: not a multiple of the time unit {:?}", ns, time_unit
+                );
+                Self::Fixed(ns / scale)
+            },
+            _ => polars_bail!(
+                ComputeError: "cannot truncate to {}: expected a single positive number of months, days or nanoseconds", every
+            ),
+        };
+        Ok(interval)
+    }
+
+    /// Truncates a timestamp in `time_unit`, interpreted as naive (wall clock) time.
+    fn truncate(self, value: i64, time_unit: TimeUnit) -> PolarsResult<i64> {
+        let truncated = match self {
+            Self::Fixed(every) => value.checked_sub(value.rem_euclid(every)),
+            Self::Months(every) => {
+                timestamp_to_naive_datetime_opt(value, time_unit).and_then(|datetime| {
+                    let date = datetime.date();
+                    let months = (date.year() as i64 - 1970) * 12 + date.month0() as i64;
+                    let months = months - months.rem_euclid(every);
+                    let date = NaiveDate::from_ymd_opt(
+                        (1970 + months.div_euclid(12)) as i32,
+                        months.rem_euclid(12) as u32 + 1,
+                        1,
+                    )?;
+                    naive_datetime_to_timestamp(date.and_time(NaiveTime::MIN), time_unit)
+                })
+            },
+            Self::Days(every) => {
+                timestamp_to_naive_datetime_opt(value, time_unit).and_then(|datetime| {
+                    let epoch = NaiveDateTime::UNIX_EPOCH.date();
+                    let days = datetime.date().signed_duration_since(epoch).num_days();
+                    let date =
+                        epoch.checked_add_signed(TimeDelta::days(days - days.rem_euclid(every)))?;
+                    naive_datetime_to_timestamp(date.and_time(NaiveTime::MIN), time_unit)
+                })
+            },
+        };
+        truncated.ok_or_else(|| out_of_range(value, time_unit))
+    }
+}
+
+fn out_of_range(value: i64, time_unit: TimeUnit) -> PolarsError {
+    polars_err!(ComputeError: "truncating timestamp {} ({:?}) is out of range", value, time_unit)
+}
+
+fn naive_datetime_to_timestamp(datetime: NaiveDateTime, time_unit: TimeUnit) -> Option<i64> {
+    let utc = datetime.and_utc();
+    match time_unit {
+        TimeUnit::Second => Some(utc.timestamp()),
+        TimeUnit::Millisecond => Some(utc.timestamp_millis()),
+        TimeUnit::Microsecond => Some(utc.timestamp_micros()),
+        TimeUnit::Nanosecond => utc.timestamp_nanos_opt(),
+    }
+}
+
+/// Version of [`try_unary`](super::arity::try_unary) that only applies `op` to valid slots.
+fn try_unary_valid<I, F, O>(
+    array: &PrimitiveArray<I>,
+    op: F,
+    data_type: ArrowDataType,
+) -> PolarsResult<PrimitiveArray<O>>
+where
+    I: NativeType,
+    O: NativeType,
+    F: Fn(I) -> PolarsResult<O>,
+{
+    let values = array
+        .iter()
+        .map(|x| x.map(|x| op(*x)).transpose())
+        .collect::<PolarsResult<Vec<_>>>()?;
+    Ok(PrimitiveArray::<O>::from(values).to(data_type))
+}
+
+#[cfg(feature = "chrono-tz")]
+fn truncate_chrono_tz(
+    array: &PrimitiveArray<i64>,
+    time_unit: TimeUnit,
+    timezone_str: &str,
+    interval: Interval,
+) -> PolarsResult<PrimitiveArray<i64>> {
+    let timezone = parse_offset_tz(timezone_str)?;
+    truncate_tz_impl(array, time_unit, timezone, timezone_str, interval)
+}
+
+#[cfg(not(feature = "chrono-tz"))]
+fn truncate_chrono_tz(
+    _: &PrimitiveArray<i64>,
+    _: TimeUnit,
+    timezone_str: &str,
+    _: Interval,
+) -> PolarsResult<PrimitiveArray<i64>> {
+    panic!(
+        "timezone \"{}\" cannot be parsed (feature chrono-tz is not active)",
+        timezone_str
+    )
+}
+
+fn truncate_tz_impl<T: chrono::TimeZone>(
+    array: &PrimitiveArray<i64>,
+    time_unit: TimeUnit,
+    timezone: T,
+    timezone_str: &str,
+    interval: Interval,
+) -> PolarsResult<PrimitiveArray<i64>> {
+    let op = |x| {
+        let utc = timestamp_to_naive_datetime_opt(x, time_unit)
+            .ok_or_else(|| out_of_range(x, time_unit))?;
+        let original_local = timezone.from_utc_datetime(&utc).naive_local();
+        let local = naive_datetime_to_timestamp(original_local, time_unit)
+            .ok_or_else(|| out_of_range(x, time_unit))?;
+        let local = interval.truncate(local, time_unit)?;
+        let local = timestamp_to_naive_datetime_opt(local, time_unit)
+            .ok_or_else(|| out_of_range(x, time_unit))?;
+
+        let truncated = match (timezone.from_local_datetime(&local), interval) {
+            (LocalResult::Single(datetime), _) => datetime.naive_utc(),
+            // A calendar interval maps the whole local day or month to the instant it starts.
+            (LocalResult::Ambiguous(earliest, _), Interval::Months(_) | Interval::Days(_)) => {
+                earliest.naive_utc()
+            },
+            (LocalResult::None, Interval::Months(_) | Interval::Days(_)) => {
+                end_of_gap(&timezone, local).ok_or_else(|| out_of_range(x, time_unit))?
+            },
+            // A fixed interval stays on the side of the transition the original value is on.
+            (LocalResult::Ambiguous(earliest, latest), Interval::Fixed(_)) => {
+                let original = timezone.from_local_datetime(&original_local).earliest();
+                if original.map(|datetime| datetime.naive_utc()) == Some(utc) {
+                    earliest.naive_utc()
+                } else {
+                    latest.naive_utc()
+                }
+            },
+            (LocalResult::None, Interval::Fixed(_)) => polars_bail!(
+                ComputeError: "truncated datetime {} is non-existent in timezone {}", local, timezone_str
+            ),
+        };
+        naive_datetime_to_timestamp(truncated, time_unit).ok_or_else(|| out_of_range(x, time_unit))
+    };
+    try_unary_valid(array, op, array.data_type().clone())
+}
+
+/// Returns the first instant (in UTC) whose local time in `timezone` is at or after `local`,
+/// which falls in a gap, e.g. a midnight skipped by the start of DST.
+fn end_of_gap<T: chrono::TimeZone>(timezone: &T, local: NaiveDateTime) -> Option<NaiveDateTime> {
+    let to_local = |seconds| {
+        timezone
+            .from_utc_datetime(&timestamp_s_to_datetime(seconds))
+            .naive_local()
+    };
+    // With the offset in effect before the gap, `local` maps to an instant after it.
+    let before = timezone
+        .from_local_datetime(&local.checked_sub_signed(TimeDelta::days(1))?)
+        .earliest()?;
+    let mut high = local.and_utc().timestamp() - before.offset().fix().local_minus_utc() as i64;
+    let mut low = high - SECONDS_IN_DAY;
+
+    // Invariant: `to_local(low) < local <= to_local(high)`.
+    while high - low > 1 {
+        let mid = low + (high - low) / 2;
+        if to_local(mid) < local {
+            low = mid
+        } else {
+            high = mid
+        }
+    }
+    Some(timestamp_s_to_datetime(high))
+}
+
 fn date_variants<F, O>(
     array: &dyn Array,
     data_type: ArrowDataType,
@@ -374,6 +712,16 @@ pub fn can_iso_week(data_type: &ArrowDataType) -> bool {
     can_date(data_type)
 }
 
+/// Checks if an array of type `data_type` can perform quarter operation
+pub fn can_quarter(data_type: &ArrowDataType) -> bool {
+    can_date(data_type)
+}
+
+/// Checks if an array of type `data_type` can perform truncate operation
+pub fn can_truncate(data_type: &ArrowDataType) -> bool {
+    can_date(data_type)
+}
+
 fn can_date(data_type: &ArrowDataType) -> bool {
     matches!(
         data_type,
@@ -405,6 +753,16 @@ pub fn can_second(data_type: &ArrowDataType) -> bool {
     can_time(data_type)
 }
 
+/// Checks if an array of type `datatype` can perform millisecond operation
+pub fn can_millisecond(data_type: &ArrowDataType) -> bool {
+    can_time(data_type)
+}
+
+/// Checks if an array of type `datatype` can perform microsecond operation
+pub fn can_microsecond(data_type: &ArrowDataType) -> bool {
+    can_time(data_type)
+}
+
 /// Checks if an array of type `datatype` can perform nanosecond operation
 pub fn can_nanosecond(data_type: &ArrowDataType) -> bool {
     can_time(data_type)
@@ -201,6 +201,21 @@ pub(crate) fn timestamp_to_naive_datetime(
     }
 }
 
+/// Converts a timestamp in `time_unit` into [`chrono::NaiveDateTime`],
+/// or `None` if it is out of range.
+#[inline]
+pub(crate) fn timestamp_to_naive_datetime_opt(
+    timestamp: i64,
+    time_unit: TimeUnit,
+) -> Option<chrono::NaiveDateTime> {
+    match time_unit {
+        TimeUnit::Second => timestamp_s_to_datetime_opt(timestamp),
+        TimeUnit::Millisecond => timestamp_ms_to_datetime_opt(timestamp),
+        TimeUnit::Microsecond => timestamp_us_to_datetime_opt(timestamp),
+        TimeUnit::Nanosecond => timestamp_ns_to_datetime_opt(timestamp),
+    }
+}
+
 /// Converts a timestamp in `time_unit` and `timezone` into [`chrono::DateTime`].
 #[inline]
 pub fn timestamp_to_datetime<T: chrono::TimeZone>(
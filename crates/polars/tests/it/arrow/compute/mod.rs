@@ -2,5 +2,7 @@ mod aggregate;
 mod bitwise;
 mod boolean;
 mod boolean_kleene;
+#[cfg(feature = "temporal")]
+mod temporal;
 
 mod arity_assign;
@@ -0,0 +1,365 @@
+use arrow::array::*;
+use arrow::compute::temporal::*;
+use arrow::datatypes::*;
+use arrow::types::months_days_ns;
+
+const HOUR: months_days_ns = months_days_ns(0, 0, 3_600_000_000_000);
+const DAY: months_days_ns = months_days_ns(0, 1, 0);
+const MONTH: months_days_ns = months_days_ns(1, 0, 0);
+
+#[test]
+fn test_time_like_time64() {
+    // 01:02:03.456789012
+    let array = Int64Array::from(&[Some(3_723_456_789_012), None])
+        .to(ArrowDataType::Time64(TimeUnit::Nanosecond));
+
+    assert_eq!(hour(&array).unwrap(), Int8Array::from(&[Some(1), None]));
+    assert_eq!(
+        nanosecond(&array).unwrap(),
+        Int32Array::from(&[Some(456_789_012), None])
+    );
+    assert_eq!(
+        millisecond(&array).unwrap(),
+        Int32Array::from(&[Some(456), None])
+    );
+}
+
+#[test]
+fn test_millisecond() {
+    // 01:02:03.456
+    let array =
+        Int32Array::from(&[Some(3_723_456), None]).to(ArrowDataType::Time32(TimeUnit::Millisecond));
+    let expected = Int32Array::from(&[Some(456), None]);
+    assert_eq!(millisecond(&array).unwrap(), expected);
+
+    let array = Int64Array::from(&[Some(3_723_456_789), None])
+        .to(ArrowDataType::Time64(TimeUnit::Microsecond));
+    assert_eq!(millisecond(&array).unwrap(), expected);
+
+    // 1970-01-01T01:00:01.456+01:00
+    let array = Int64Array::from(&[Some(1_456), None]).to(ArrowDataType::Timestamp(
+        TimeUnit::Millisecond,
+        Some("+01:00".to_string()),
+    ));
+    assert_eq!(millisecond(&array).unwrap(), expected);
+}
+
+#[test]
+#[cfg(feature = "timezones")]
+fn test_millisecond_chrono_tz() {
+    // 1970-01-01T01:00:01.456789012+01:00
+    let array = Int64Array::from(&[Some(1_456_789_012), None]).to(ArrowDataType::Timestamp(
+        TimeUnit::Nanosecond,
+        Some("Europe/Paris".to_string()),
+    ));
+    assert_eq!(
+        millisecond(&array).unwrap(),
+        Int32Array::from(&[Some(456), None])
+    );
+}
+
+#[test]
+fn test_microsecond() {
+    let expected = Int32Array::from(&[Some(456_789), None]);
+
+    // 01:02:03.456
+    let array =
+        Int32Array::from(&[Some(3_723_456), None]).to(ArrowDataType::Time32(TimeUnit::Millisecond));
+    assert_eq!(
+        microsecond(&array).unwrap(),
+        Int32Array::from(&[Some(456_000), None])
+    );
+
+    // 01:02:03.456789
+    let array = Int64Array::from(&[Some(3_723_456_789), None])
+        .to(ArrowDataType::Time64(TimeUnit::Microsecond));
+    assert_eq!(microsecond(&array).unwrap(), expected);
+
+    // 01:02:03.456789012
+    let array = Int64Array::from(&[Some(3_723_456_789_012), None])
+        .to(ArrowDataType::Time64(TimeUnit::Nanosecond));
+    assert_eq!(microsecond(&array).unwrap(), expected);
+
+    // 1970-01-01T01:00:01.456789+01:00
+    let array = Int64Array::from(&[Some(1_456_789), None]).to(ArrowDataType::Timestamp(
+        TimeUnit::Microsecond,
+        Some("+01:00".to_string()),
+    ));
+    assert_eq!(microsecond(&array).unwrap(), expected);
+}
+
+#[test]
+#[cfg(feature = "timezones")]
+fn test_microsecond_chrono_tz() {
+    // 1970-01-01T01:00:01.456789012+01:00
+    let array = Int64Array::from(&[Some(1_456_789_012), None]).to(ArrowDataType::Timestamp(
+        TimeUnit::Nanosecond,
+        Some("Europe/Paris".to_string()),
+    ));
+    assert_eq!(
+        microsecond(&array).unwrap(),
+        Int32Array::from(&[Some(456_789), None])
+    );
+}
+
+#[test]
+fn test_quarter() {
+    let expected = Int8Array::from(&[Some(1), Some(1), Some(2), Some(4), None]);
+
+    // 2023-01-15, 2023-03-31, 2023-04-01, 2023-12-31
+    let days = [Some(19_372), Some(19_447), Some(19_448), Some(19_722), None];
+    let array = Int32Array::from(&days).to(ArrowDataType::Date32);
+    assert_eq!(quarter(&array).unwrap(), expected);
+
+    let milliseconds = days.map(|x| x.map(|x| x as i64 * 86_400_000));
+    let array = Int64Array::from(&milliseconds).to(ArrowDataType::Date64);
+    assert_eq!(quarter(&array).unwrap(), expected);
+
+    // at 23:30 on each of those days
+    let seconds = days.map(|x| x.map(|x| x as i64 * 86_400 + 84_600));
+    let array = timestamps(&seconds, None);
+    assert_eq!(quarter(&array).unwrap(), expected);
+
+    // 2023-12-31T23:30:00Z is already 2024 in local time
+    let array = timestamps(&[Some(1_704_065_400)], Some("+01:00"));
+    assert_eq!(quarter(&array).unwrap(), Int8Array::from(&[Some(1)]));
+}
+
+#[test]
+#[cfg(feature = "timezones")]
+fn test_quarter_chrono_tz() {
+    // 2023-03-31T20:00:00Z is 2023-04-01T05:00:00+09:00
+    let array = timestamps(&[Some(1_680_292_800), None], Some("Asia/Tokyo"));
+    assert_eq!(quarter(&array).unwrap(), Int8Array::from(&[Some(2), None]));
+}
+
+fn timestamps(values: &[Option<i64>], timezone: Option<&str>) -> Int64Array {
+    Int64Array::from(values).to(ArrowDataType::Timestamp(
+        TimeUnit::Second,
+        timezone.map(|tz| tz.to_string()),
+    ))
+}
+
+fn truncate_i64(array: &dyn Array, every: months_days_ns) -> Int64Array {
+    let result = truncate(array, every).unwrap();
+    assert_eq!(result.data_type(), array.data_type());
+    result
+        .as_any()
+        .downcast_ref::<Int64Array>()
+        .unwrap()
+        .clone()
+        .to(ArrowDataType::Int64)
+}
+
+#[test]
+fn test_truncate_timestamp() {
+    // 2024-03-10T07:30:00, 1969-12-31T23:59:59
+    let array = timestamps(&[Some(1_710_055_800), None, Some(-1)], None);
+
+    let expected = Int64Array::from(&[Some(1_710_054_000), None, Some(-3_600)]);
+    assert_eq!(truncate_i64(&array, HOUR), expected);
+
+    let expected = Int64Array::from(&[Some(1_710_028_800), None, Some(-86_400)]);
+    assert_eq!(truncate_i64(&array, DAY), expected);
+
+    let expected = Int64Array::from(&[Some(1_709_251_200), None, Some(-2_678_400)]);
+    assert_eq!(truncate_i64(&array, MONTH), expected);
+
+    // quarters are counted from 1970-01
+    let expected = Int64Array::from(&[Some(1_704_067_200), None, Some(-7_948_800)]);
+    assert_eq!(truncate_i64(&array, months_days_ns(3, 0, 0)), expected);
+}
+
+#[test]
+fn test_truncate_date32() {
+    // 1970-02-15, 1969-12-31
+    let array = Int32Array::from(&[Some(45), None, Some(-1)]).to(ArrowDataType::Date32);
+
+    let result = truncate(&array, MONTH).unwrap();
+    let expected = Int32Array::from(&[Some(31), None, Some(-31)]).to(ArrowDataType::Date32);
+    assert_eq!(
+        result.as_any().downcast_ref::<Int32Array>(),
+        Some(&expected)
+    );
+
+    for every in [HOUR, DAY] {
+        let result = truncate(&array, every).unwrap();
+        assert_eq!(result.as_any().downcast_ref::<Int32Array>(), Some(&array));
+    }
+
+    // days are counted from 1970-01-01, a Thursday
+    let result = truncate(&array, months_days_ns(0, 7, 0)).unwrap();
+    let expected = Int32Array::from(&[Some(42), None, Some(-7)]).to(ArrowDataType::Date32);
+    assert_eq!(
+        result.as_any().downcast_ref::<Int32Array>(),
+        Some(&expected)
+    );
+}
+
+#[test]
+fn test_truncate_date64() {
+    // 1970-02-15, 1969-12-31
+    let array =
+        Int64Array::from(&[Some(3_888_000_000), None, Some(-86_400_000)]).to(ArrowDataType::Date64);
+
+    let expected = Int64Array::from(&[Some(2_678_400_000), None, Some(-2_678_400_000)]);
+    assert_eq!(truncate_i64(&array, MONTH), expected);
+
+    let expected = array.clone().to(ArrowDataType::Int64);
+    assert_eq!(truncate_i64(&array, DAY), expected);
+    assert_eq!(truncate_i64(&array, HOUR), expected);
+}
+
+#[test]
+fn test_truncate_invalid_interval() {
+    let array = timestamps(&[Some(0)], None);
+    let date32 = Int32Array::from(&[Some(0)]).to(ArrowDataType::Date32);
+
+    // zero or negative
+    assert!(truncate(&array, months_days_ns(0, 0, 0)).is_err());
+    assert!(truncate(&array, months_days_ns(-1, 0, 0)).is_err());
+    assert!(truncate(&array, months_days_ns(0, -1, 0)).is_err());
+    assert!(truncate(&array, months_days_ns(0, 0, -1)).is_err());
+    // mixed components
+    assert!(truncate(&array, months_days_ns(1, 1, 0)).is_err());
+    assert!(truncate(&array, months_days_ns(0, 1, 1_000_000_000)).is_err());
+    // not a multiple of the time unit
+    assert!(truncate(&array, months_days_ns(0, 0, 1_000_000)).is_err());
+    assert!(truncate(&date32, months_days_ns(0, 0, 1_000_000)).is_err());
+}
+
+#[test]
+fn test_truncate_out_of_range() {
+    let array = Int64Array::from(&[Some(i64::MIN)])
+        .to(ArrowDataType::Timestamp(TimeUnit::Nanosecond, None));
+    assert!(truncate(&array, HOUR).is_err());
+
+    let array = Int32Array::from(&[Some(i32::MAX)]).to(ArrowDataType::Date32);
+    for every in [DAY, MONTH] {
+        assert!(truncate(&array, every).is_err());
+    }
+
+    let array = Int64Array::from(&[Some(i64::MAX)])
+        .to(ArrowDataType::Timestamp(TimeUnit::Millisecond, None));
+    for every in [DAY, MONTH] {
+        assert!(truncate(&array, every).is_err());
+    }
+
+    let array = Int64Array::from(&[Some(i64::MAX)]).to(ArrowDataType::Timestamp(
+        TimeUnit::Millisecond,
+        Some("+01:00".to_string()),
+    ));
+    for every in [HOUR, DAY, MONTH] {
+        assert!(truncate(&array, every).is_err());
+    }
+}
+
+#[test]
+#[cfg(feature = "timezones")]
+fn test_truncate_out_of_range_chrono_tz() {
+    let array = Int64Array::from(&[Some(i64::MAX)]).to(ArrowDataType::Timestamp(
+        TimeUnit::Millisecond,
+        Some("Europe/Paris".to_string()),
+    ));
+    for every in [HOUR, DAY, MONTH] {
+        assert!(truncate(&array, every).is_err());
+    }
+}
+
+#[test]
+fn test_truncate_unsupported_type() {
+    let array = Int64Array::from(&[Some(0)]);
+    assert!(truncate(&array, DAY).is_err());
+}
+
+#[test]
+fn test_truncate_fixed_offset() {
+    // 1970-01-01T05:30:00+05:30
+    let array = timestamps(&[Some(0), None], Some("+05:30"));
+
+    let expected = Int64Array::from(&[Some(-1_800), None]);
+    assert_eq!(truncate_i64(&array, HOUR), expected);
+
+    let expected = Int64Array::from(&[Some(-19_800), None]);
+    assert_eq!(truncate_i64(&array, DAY), expected);
+}
+
+#[test]
+#[cfg(feature = "timezones")]
+fn test_truncate_dst_new_york() {
+    // 2024-03-10T03:30:00-04:00, right after the clocks sprang forward
+    let array = timestamps(&[Some(1_710_055_800)], Some("America/New_York"));
+    // 2024-03-10T00:00:00-05:00
+    assert_eq!(
+        truncate_i64(&array, DAY),
+        Int64Array::from(&[Some(1_710_046_800)])
+    );
+    // 2024-03-10T03:00:00-04:00
+    assert_eq!(
+        truncate_i64(&array, HOUR),
+        Int64Array::from(&[Some(1_710_054_000)])
+    );
+
+    // 2024-11-03T01:30:00 both before (-04:00) and after (-05:00) the clocks fell back
+    let array = timestamps(
+        &[Some(1_730_611_800), Some(1_730_615_400)],
+        Some("America/New_York"),
+    );
+    // 2024-11-03T01:00:00 on the same side of the transition
+    assert_eq!(
+        truncate_i64(&array, HOUR),
+        Int64Array::from(&[Some(1_730_610_000), Some(1_730_613_600)])
+    );
+    // 2024-11-03T00:00:00-04:00
+    assert_eq!(
+        truncate_i64(&array, DAY),
+        Int64Array::from(&[Some(1_730_606_400), Some(1_730_606_400)])
+    );
+}
+
+#[test]
+#[cfg(feature = "timezones")]
+fn test_truncate_dst_at_midnight() {
+    // Havana falls back from 01:00 CDT to 00:00 CST on 2026-11-01, so its midnight is
+    // ambiguous: 2026-11-01T00:30:00 CDT and CST, 2026-11-15T12:00:00 CST
+    let array = timestamps(
+        &[
+            Some(1_793_507_400),
+            Some(1_793_511_000),
+            Some(1_794_762_000),
+        ],
+        Some("America/Havana"),
+    );
+    // the month starts at 2026-11-01T00:00:00 CDT
+    let expected = Int64Array::from(&[Some(1_793_505_600); 3]);
+    assert_eq!(truncate_i64(&array, MONTH), expected);
+    // as does the day, for both sides of the transition, 2026-11-15T00:00:00 CST
+    let expected = Int64Array::from(&[
+        Some(1_793_505_600),
+        Some(1_793_505_600),
+        Some(1_794_718_800),
+    ]);
+    assert_eq!(truncate_i64(&array, DAY), expected);
+    // 2026-11-01T00:00:00 on the same side of the transition, 2026-11-15T12:00:00 CST
+    let expected = Int64Array::from(&[
+        Some(1_793_505_600),
+        Some(1_793_509_200),
+        Some(1_794_762_000),
+    ]);
+    assert_eq!(truncate_i64(&array, HOUR), expected);
+
+    // Havana springs forward from 00:00 CST to 01:00 CDT on 2026-03-08, skipping midnight:
+    // 2026-03-08T01:30:00 CDT, 2026-03-08T12:00:00 CDT
+    let array = timestamps(
+        &[Some(1_772_947_800), Some(1_772_985_600)],
+        Some("America/Havana"),
+    );
+    // the day starts at 2026-03-08T01:00:00 CDT
+    let expected = Int64Array::from(&[Some(1_772_946_000); 2]);
+    assert_eq!(truncate_i64(&array, DAY), expected);
+    // 2026-03-01T00:00:00 CST
+    let expected = Int64Array::from(&[Some(1_772_341_200); 2]);
+    assert_eq!(truncate_i64(&array, MONTH), expected);
+    // a fixed duration truncating into the skipped midnight is an error
+    assert!(truncate(&array, months_days_ns(0, 0, 2 * 3_600_000_000_000)).is_err());
+}